@@ -5,6 +5,11 @@ extern crate env_logger;
 extern crate config;
 
 use std::env;
+// NOTE: `ServerProxyConfig` is built below with `address` only; it has no
+// `request_log` section, and the forwarding path started here does not time
+// or log completed commands. The completed-command/slowlog logging in
+// `undermoon::proxy::request_log` (`RequestLogConfig`, `CommandTimer`) has
+// no caller in this binary.
 use undermoon::proxy::executor::SharedForwardHandler;
 use undermoon::proxy::service::{ServerProxyService, ServerProxyConfig};
 
@@ -0,0 +1,133 @@
+use futures::channel::oneshot;
+use futures::task::{Context, Poll};
+use futures::Future;
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::{self, Delay};
+
+/// An envelope sent to a worker: the message plus a `Reply` to send the
+/// result back through.
+pub struct Request<M, T, E> {
+    pub msg: M,
+    pub reply: Reply<T, E>,
+}
+
+/// The sending half of a one-shot reply channel, paired with a `ReplyHandle`.
+pub struct Reply<T, E> {
+    sender: oneshot::Sender<Result<T, E>>,
+}
+
+impl<T, E> Reply<T, E> {
+    pub fn send(self, result: Result<T, E>) {
+        if self.sender.send(result).is_err() {
+            debug!("reply receiver dropped before the result was sent");
+        }
+    }
+
+    /// Whether the paired `ReplyHandle` was dropped, e.g. by a timeout.
+    pub fn is_canceled(&self) -> bool {
+        self.sender.is_canceled()
+    }
+}
+
+/// Creates a `Request`/`ReplyHandle` pair for a call with the given timeout.
+pub fn new_request<M, T, E>(
+    msg: M,
+    timeout: Duration,
+) -> (Request<M, T, E>, ReplyHandle<T, E>) {
+    let (sender, receiver) = oneshot::channel();
+    let request = Request {
+        msg,
+        reply: Reply { sender },
+    };
+    let handle = ReplyHandle {
+        receiver,
+        timeout: time::delay_for(timeout),
+    };
+    (request, handle)
+}
+
+#[pin_project]
+pub struct ReplyHandle<T, E> {
+    #[pin]
+    receiver: oneshot::Receiver<Result<T, E>>,
+    #[pin]
+    timeout: Delay,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IntercomError<E> {
+    // The worker never replied within the configured timeout.
+    Timeout,
+    // The `Reply` was dropped without sending, e.g. the worker was cancelled.
+    Cancelled,
+    Failed(E),
+}
+
+impl<T, E> Future for ReplyHandle<T, E> {
+    type Output = Result<T, IntercomError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(result) = this.receiver.poll(cx) {
+            return Poll::Ready(match result {
+                Ok(Ok(value)) => Ok(value),
+                Ok(Err(err)) => Err(IntercomError::Failed(err)),
+                Err(_) => Err(IntercomError::Cancelled),
+            });
+        }
+
+        this.timeout.poll(cx).map(|_| Err(IntercomError::Timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn test_reply_resolves_the_handle() {
+        let mut rt = Runtime::new().expect("test_reply_resolves_the_handle");
+        let res = rt.block_on(async {
+            let (request, handle) = new_request::<_, u32, ()>("msg", Duration::from_secs(5));
+            request.reply.send(Ok(42));
+            handle.await
+        });
+        assert_eq!(res, Ok(42));
+    }
+
+    #[test]
+    fn test_reply_handle_times_out() {
+        let mut rt = Runtime::new().expect("test_reply_handle_times_out");
+        let res = rt.block_on(async {
+            let (_request, handle) = new_request::<_, u32, ()>("msg", Duration::from_millis(10));
+            handle.await
+        });
+        assert!(matches!(res, Err(IntercomError::Timeout)));
+    }
+
+    #[test]
+    fn test_dropping_the_reply_is_observed_as_cancelled() {
+        let mut rt = Runtime::new().expect("test_dropping_the_reply_is_observed_as_cancelled");
+        let res = rt.block_on(async {
+            let (request, handle) = new_request::<_, u32, ()>("msg", Duration::from_secs(5));
+            drop(request.reply);
+            handle.await
+        });
+        assert!(matches!(res, Err(IntercomError::Cancelled)));
+    }
+
+    #[test]
+    fn test_dropping_the_handle_is_observed_by_the_reply() {
+        let mut rt = Runtime::new().expect("test_dropping_the_handle_is_observed_by_the_reply");
+        rt.block_on(async {
+            let (request, handle) = new_request::<_, u32, ()>("msg", Duration::from_secs(5));
+            assert!(!request.reply.is_canceled());
+            drop(handle);
+            assert!(request.reply.is_canceled());
+        });
+    }
+}
@@ -1,14 +1,47 @@
 use super::broker::{MetaDataBrokerError, MetaManipulationBrokerError};
 use crate::common::cluster::{Host, MigrationTaskMeta};
+use crate::common::intercom;
 use crate::protocol::RedisClientError;
 use futures::{future, stream, Future, FutureExt, Stream, StreamExt, TryFutureExt};
-use futures_batch::ChunksTimeoutStreamExt;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
 use std::io;
 use std::pin::Pin;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time;
+use tracing::{field, Instrument, Span};
+
+/// A value paired with the `tracing::Span` of the request it belongs to.
+pub struct Request<T> {
+    value: T,
+    span: Span,
+}
+
+impl<T> Request<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { value, span }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    /// Derives the next stage's value and span, with this span entered.
+    pub fn map<U>(&self, f: impl FnOnce(&T) -> (U, Span)) -> Request<U> {
+        let (value, span) = self.span.in_scope(|| f(&self.value));
+        Request { value, span }
+    }
+}
 
 pub trait ProxiesRetriever: Sync + Send + 'static {
     fn retrieve_proxies<'s>(
@@ -41,6 +74,34 @@ pub trait FailureDetector {
     fn run<'s>(&'s self) -> Pin<Box<dyn Future<Output = Result<(), CoordinateError>> + Send + 's>>;
 }
 
+// Default number of proxies that may be checked concurrently when no
+// explicit concurrency limit is given through `with_concurrency`.
+const DEFAULT_CHECK_CONCURRENCY: usize = 30;
+// A proxy must fail this many consecutive rounds, spanning at least
+// `DEFAULT_GRACE_PERIOD`, before it is reported as down.
+const DEFAULT_FAILURE_THRESHOLD: usize = 3;
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(30);
+// Bounded retry/backoff used to ride out a single transient blip before a
+// round is even counted as a failure.
+const RECHECK_ATTEMPTS: usize = 2;
+const RECHECK_BACKOFF: Duration = Duration::from_millis(100);
+// How long to wait for an `intercom`-routed call to reply before treating
+// it as failed.
+const REPORT_TIMEOUT: Duration = Duration::from_secs(5);
+const COMMIT_TIMEOUT: Duration = Duration::from_secs(5);
+const SEND_META_TIMEOUT: Duration = Duration::from_secs(5);
+// How often a spawned intercom worker checks whether its `Reply` was
+// cancelled, so it can give up on work nobody is waiting for anymore.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// Per-address state used to debounce flapping proxies: how many rounds in
+// a row `FailureChecker::check` has reported this address as failed, and
+// when the streak started.
+struct FailureState {
+    consecutive_failures: usize,
+    first_failure_at: Instant,
+}
+
 pub struct SeqFailureDetector<
     Retriever: ProxiesRetriever,
     Checker: FailureChecker,
@@ -49,62 +110,206 @@ pub struct SeqFailureDetector<
     retriever: Retriever,
     checker: Arc<Checker>,
     reporter: Arc<Reporter>,
+    concurrency: usize,
+    failure_threshold: usize,
+    grace_period: Duration,
+    failure_states: Arc<Mutex<HashMap<String, FailureState>>>,
+}
+
+// Rides out a single transient blip: keeps re-checking `address` a few
+// times with a short backoff as long as it keeps failing, so one round
+// only counts as failed once the checker has agreed a few times in a row.
+async fn recheck<C: FailureChecker>(
+    checker: &C,
+    address: String,
+) -> Result<Option<String>, CoordinateError> {
+    let mut result = checker.check(address.clone()).await?;
+    let mut attempt = 0;
+    while result.is_some() && attempt < RECHECK_ATTEMPTS {
+        time::delay_for(RECHECK_BACKOFF).await;
+        result = checker.check(address.clone()).await?;
+        attempt += 1;
+    }
+    Ok(result)
+}
+
+// Records one more failed round for `address` and reports whether it has
+// now failed `failure_threshold` consecutive rounds spanning at least
+// `grace_period`.
+fn confirm_failure(
+    failure_states: &Mutex<HashMap<String, FailureState>>,
+    failure_threshold: usize,
+    grace_period: Duration,
+    address: &str,
+) -> bool {
+    let mut states = failure_states.lock().unwrap();
+    let state = states
+        .entry(address.to_string())
+        .or_insert_with(|| FailureState {
+            consecutive_failures: 0,
+            first_failure_at: Instant::now(),
+        });
+    state.consecutive_failures += 1;
+    state.consecutive_failures >= failure_threshold && state.first_failure_at.elapsed() >= grace_period
+}
+
+fn clear_failure(failure_states: &Mutex<HashMap<String, FailureState>>, address: &str) {
+    failure_states.lock().unwrap().remove(address);
+}
+
+// Routes `FailureReporter::report` through the `intercom` request/reply
+// primitive instead of awaiting it directly, so a reporter that hangs
+// times out instead of blocking this address's slot forever.
+async fn report_failure<P: FailureReporter>(
+    reporter: &Arc<P>,
+    address: String,
+) -> Result<(), CoordinateError> {
+    let (request, handle) = intercom::new_request(address, REPORT_TIMEOUT);
+    let intercom::Request { msg: address, reply } = request;
+    let reporter = reporter.clone();
+    tokio::spawn(async move {
+        let report = reporter.report(address);
+        futures::pin_mut!(report);
+        loop {
+            if reply.is_canceled() {
+                debug!("report's reply was dropped, abandoning it");
+                return;
+            }
+            match future::select(report.as_mut(), time::delay_for(CANCEL_POLL_INTERVAL)).await {
+                future::Either::Left((result, _)) => {
+                    reply.send(result);
+                    return;
+                }
+                future::Either::Right(_) => continue,
+            }
+        }
+    });
+    handle.await.map_err(CoordinateError::from)
 }
 
 impl<T: ProxiesRetriever, C: FailureChecker, P: FailureReporter> SeqFailureDetector<T, C, P> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_concurrency(
+        retriever: T,
+        checker: C,
+        reporter: P,
+        concurrency: usize,
+        failure_threshold: usize,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            retriever,
+            checker: Arc::new(checker),
+            reporter: Arc::new(reporter),
+            concurrency,
+            failure_threshold,
+            grace_period,
+            failure_states: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn check_and_report(
         checker: &C,
-        reporter: &P,
-        address: String,
+        reporter: &Arc<P>,
+        failure_states: &Mutex<HashMap<String, FailureState>>,
+        failure_threshold: usize,
+        grace_period: Duration,
+        request: Request<String>,
     ) -> Result<(), CoordinateError> {
-        let address = match checker.check(address).await? {
-            Some(addr) => addr,
-            None => return Ok(()),
-        };
-        if let Err(err) = reporter.report(address).await {
-            error!("failed to report failure: {:?}", err);
-            return Err(err);
+        let span = request.span();
+        async move {
+            let address = request.into_value();
+            let failed_address = match recheck(checker, address.clone()).await? {
+                Some(addr) => addr,
+                None => {
+                    clear_failure(failure_states, &address);
+                    return Ok(());
+                }
+            };
+
+            if !confirm_failure(failure_states, failure_threshold, grace_period, &failed_address) {
+                debug!(
+                    "{} failed this round but has not been confirmed down yet",
+                    failed_address
+                );
+                return Ok(());
+            }
+
+            if let Err(err) = report_failure(reporter, failed_address).await {
+                error!("failed to report failure: {:?}", err);
+                Span::current().record("error", field::debug(&err));
+                return Err(err);
+            }
+            Ok(())
         }
-        Ok(())
+        .instrument(span)
+        .await
     }
 
     async fn run_impl(&self) -> Result<(), CoordinateError> {
         let checker = self.checker.clone();
         let reporter = self.reporter.clone();
-        const BATCH_SIZE: usize = 30;
-        let batch_time = Duration::from_millis(1);
+        let failure_states = self.failure_states.clone();
+        let failure_threshold = self.failure_threshold;
+        let grace_period = self.grace_period;
+        // Addresses seen this round, so that proxies no longer returned by
+        // the retriever (e.g. removed from the cluster) don't keep their
+        // debounce state around forever.
+        let seen_addresses: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
 
         let mut res = Ok(());
 
-        for results in self
+        let mut results = self
             .retriever
             .retrieve_proxies()
-            .chunks_timeout(BATCH_SIZE, batch_time)
-            .next()
-            .await
-        {
-            let mut proxies = vec![];
-            for r in results {
-                match r {
-                    Ok(proxy) => proxies.push(proxy),
-                    Err(err) => {
-                        error!("failed to get proxy: {:?}", err);
-                        res = Err(err);
+            .map(|r| {
+                let checker = checker.clone();
+                let reporter = reporter.clone();
+                let failure_states = failure_states.clone();
+                let seen_addresses = seen_addresses.clone();
+                async move {
+                    match r {
+                        Ok(address) => {
+                            seen_addresses.lock().unwrap().insert(address.clone());
+                            let span = tracing::info_span!(
+                                "check_proxy",
+                                address = %address,
+                                error = field::Empty,
+                            );
+                            let request = Request::new(address, span);
+                            Self::check_and_report(
+                                &checker,
+                                &reporter,
+                                &failure_states,
+                                failure_threshold,
+                                grace_period,
+                                request,
+                            )
+                            .await
+                        }
+                        Err(err) => {
+                            error!("failed to get proxy: {:?}", err);
+                            Err(err)
+                        }
                     }
                 }
-            }
-            let futs: Vec<_> = proxies
-                .into_iter()
-                .map(|address| Self::check_and_report(&checker, &reporter, address))
-                .collect();
-            let results = future::join_all(futs).await;
-            for r in results.into_iter() {
-                if let Err(err) = r {
-                    error!("faild to check and report error: {:?}", err);
-                    res = Err(err);
-                }
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some(r) = results.next().await {
+            if let Err(err) = r {
+                error!("faild to check and report error: {:?}", err);
+                res = Err(err);
             }
         }
+
+        let seen_addresses = seen_addresses.lock().unwrap();
+        failure_states
+            .lock()
+            .unwrap()
+            .retain(|address, _| seen_addresses.contains(address));
+
         res
     }
 }
@@ -117,11 +322,14 @@ impl<T: ProxiesRetriever, C: FailureChecker, P: FailureReporter> FailureDetector
     type Reporter = P;
 
     fn new(retriever: T, checker: C, reporter: P) -> Self {
-        Self {
+        Self::with_concurrency(
             retriever,
-            checker: Arc::new(checker),
-            reporter: Arc::new(reporter),
-        }
+            checker,
+            reporter,
+            DEFAULT_CHECK_CONCURRENCY,
+            DEFAULT_FAILURE_THRESHOLD,
+            DEFAULT_GRACE_PERIOD,
+        )
     }
 
     fn run<'s>(&'s self) -> Pin<Box<dyn Future<Output = Result<(), CoordinateError>> + Send + 's>> {
@@ -150,53 +358,56 @@ pub trait FailureHandler {
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>>;
 }
 
+const DEFAULT_HANDLE_CONCURRENCY: usize = 10;
+
 pub struct SeqFailureHandler<PFRetriever: ProxyFailureRetriever, Handler: ProxyFailureHandler> {
     proxy_failure_retriever: PFRetriever,
     handler: Arc<Handler>,
+    concurrency: usize,
 }
 
 impl<P: ProxyFailureRetriever, H: ProxyFailureHandler> SeqFailureHandler<P, H> {
+    pub fn with_concurrency(proxy_failure_retriever: P, handler: H, concurrency: usize) -> Self {
+        Self {
+            proxy_failure_retriever,
+            handler: Arc::new(handler),
+            concurrency,
+        }
+    }
+
     async fn run_impl(&self) -> Result<(), CoordinateError> {
         let handler = self.handler.clone();
-        const BATCH_SIZE: usize = 10;
-        let batch_time = Duration::from_millis(1);
 
         let mut res = Ok(());
 
-        for results in self
+        let mut results = self
             .proxy_failure_retriever
             .retrieve_proxy_failures()
-            .chunks_timeout(BATCH_SIZE, batch_time)
-            .next()
-            .await
-        {
-            let mut proxies = vec![];
-            for r in results {
-                match r {
-                    Ok(proxy) => proxies.push(proxy),
-                    Err(err) => {
-                        error!("failed to get proxy: {:?}", err);
-                        res = Err(err);
-                    }
-                }
-            }
-            let futs: Vec<_> = proxies
-                .into_iter()
-                .map(|proxy_address| {
+            .map(|r| {
+                let handler = handler.clone();
+                async move {
+                    let proxy_address = match r {
+                        Ok(proxy_address) => proxy_address,
+                        Err(err) => {
+                            error!("failed to get proxy: {:?}", err);
+                            return Err(err);
+                        }
+                    };
                     handler
                         .handle_proxy_failure(proxy_address.clone())
                         .or_else(move |err| {
                             error!("Failed to handler proxy failre {} {:?}", proxy_address, err);
                             future::ok(())
                         })
-                })
-                .collect();
-            let results = future::join_all(futs).await;
-            for r in results.into_iter() {
-                if let Err(err) = r {
-                    error!("faild to check and report error: {:?}", err);
-                    res = Err(err);
+                        .await
                 }
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some(r) = results.next().await {
+            if let Err(err) = r {
+                error!("faild to check and report error: {:?}", err);
+                res = Err(err);
             }
         }
         res
@@ -208,10 +419,7 @@ impl<P: ProxyFailureRetriever, H: ProxyFailureHandler> FailureHandler for SeqFai
     type Handler = H;
 
     fn new(proxy_failure_retriever: P, handler: H) -> Self {
-        Self {
-            proxy_failure_retriever,
-            handler: Arc::new(handler),
-        }
+        Self::with_concurrency(proxy_failure_retriever, handler, DEFAULT_HANDLE_CONCURRENCY)
     }
 
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>> {
@@ -250,6 +458,24 @@ pub trait HostMetaSynchronizer {
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>>;
 }
 
+const DEFAULT_META_SYNC_CONCURRENCY: usize = 10;
+
+// Routes `HostMetaSender::send_meta` through the `intercom` request/reply
+// primitive, same as `report_failure`.
+async fn send_meta<S: HostMetaSender>(
+    sender: &Arc<S>,
+    host: Host,
+) -> Result<(), CoordinateError> {
+    let (request, handle) = intercom::new_request(host, SEND_META_TIMEOUT);
+    let intercom::Request { msg: host, reply } = request;
+    let sender = sender.clone();
+    tokio::spawn(async move {
+        let result = sender.send_meta(host).await;
+        reply.send(result);
+    });
+    handle.await.map_err(CoordinateError::from)
+}
+
 pub struct HostMetaRespSynchronizer<
     PRetriever: ProxiesRetriever,
     MRetriever: HostMetaRetriever,
@@ -258,59 +484,99 @@ pub struct HostMetaRespSynchronizer<
     proxy_retriever: PRetriever,
     meta_retriever: Arc<MRetriever>,
     sender: Arc<Sender>,
+    concurrency: usize,
 }
 
 impl<P: ProxiesRetriever, M: HostMetaRetriever, S: HostMetaSender>
     HostMetaRespSynchronizer<P, M, S>
 {
+    pub fn with_concurrency(
+        proxy_retriever: P,
+        meta_retriever: M,
+        sender: S,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            proxy_retriever,
+            meta_retriever: Arc::new(meta_retriever),
+            sender: Arc::new(sender),
+            concurrency,
+        }
+    }
+
     async fn retrieve_and_send_meta(
         meta_retriever: &M,
-        sender: &S,
-        address: String,
+        sender: &Arc<S>,
+        request: Request<String>,
     ) -> Result<(), CoordinateError> {
-        let host_opt = meta_retriever.get_host_meta(address).await?;
-        let host = match host_opt {
-            Some(host) => host,
-            None => return Ok(()),
-        };
-        if let Err(err) = sender.send_meta(host).await {
-            error!("failed to set meta: {:?}", err);
-            return Err(err);
+        let span = request.span();
+        async move {
+            let address = request.value().clone();
+            let host_opt = meta_retriever.get_host_meta(address).await?;
+            let host = match host_opt {
+                Some(host) => host,
+                None => return Ok(()),
+            };
+            let request = request.map(move |address| {
+                let span = tracing::info_span!(
+                    "send_meta",
+                    address = %address,
+                    error = field::Empty,
+                );
+                (host, span)
+            });
+            let span = request.span();
+            async move {
+                if let Err(err) = send_meta(sender, request.into_value()).await {
+                    error!("failed to set meta: {:?}", err);
+                    Span::current().record("error", field::debug(&err));
+                    return Err(err);
+                }
+                Ok(())
+            }
+            .instrument(span)
+            .await
         }
-        Ok(())
+        .instrument(span)
+        .await
     }
 
     async fn run_impl(&self) -> Result<(), CoordinateError> {
         let meta_retriever = self.meta_retriever.clone();
         let sender = self.sender.clone();
-        let batch_time = Duration::from_millis(1);
 
         let mut res = Ok(());
-        let mut s = self
+
+        let mut results = self
             .proxy_retriever
             .retrieve_proxies()
-            .chunks_timeout(10, batch_time);
-        for results in s.next().await {
-            let mut proxies = vec![];
-            for r in results {
-                match r {
-                    Ok(proxy) => proxies.push(proxy),
-                    Err(err) => {
-                        error!("failed to get proxy: {:?}", err);
-                        res = Err(err);
+            .map(|r| {
+                let meta_retriever = meta_retriever.clone();
+                let sender = sender.clone();
+                async move {
+                    match r {
+                        Ok(address) => {
+                            let span = tracing::info_span!(
+                                "retrieve_and_send_meta",
+                                address = %address,
+                                error = field::Empty,
+                            );
+                            let request = Request::new(address, span);
+                            Self::retrieve_and_send_meta(&meta_retriever, &sender, request).await
+                        }
+                        Err(err) => {
+                            error!("failed to get proxy: {:?}", err);
+                            Err(err)
+                        }
                     }
                 }
-            }
-            let futs: Vec<_> = proxies
-                .into_iter()
-                .map(|address| Self::retrieve_and_send_meta(&meta_retriever, &sender, address))
-                .collect();
-            let results = future::join_all(futs).await;
-            for r in results.into_iter() {
-                if let Err(err) = r {
-                    error!("faild to retrieve and send meta, error: {:?}", err);
-                    res = Err(err);
-                }
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some(r) = results.next().await {
+            if let Err(err) = r {
+                error!("faild to retrieve and send meta, error: {:?}", err);
+                res = Err(err);
             }
         }
         res
@@ -329,11 +595,12 @@ impl<P: ProxiesRetriever, M: HostMetaRetriever, S: HostMetaSender> HostMetaSynch
         meta_retriever: Self::MRetriever,
         sender: Self::Sender,
     ) -> Self {
-        Self {
+        Self::with_concurrency(
             proxy_retriever,
-            meta_retriever: Arc::new(meta_retriever),
-            sender: Arc::new(sender),
-        }
+            meta_retriever,
+            sender,
+            DEFAULT_META_SYNC_CONCURRENCY,
+        )
     }
 
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>> {
@@ -376,6 +643,24 @@ pub trait MigrationStateSynchronizer: Sync + Send + 'static {
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>>;
 }
 
+const DEFAULT_MIGRATION_SYNC_CONCURRENCY: usize = 10;
+
+// Routes `MigrationCommitter::commit` through the `intercom` request/reply
+// primitive, same as `report_failure`.
+async fn commit_migration<MC: MigrationCommitter>(
+    committer: &Arc<MC>,
+    meta: MigrationTaskMeta,
+) -> Result<(), CoordinateError> {
+    let (request, handle) = intercom::new_request(meta, COMMIT_TIMEOUT);
+    let intercom::Request { msg: meta, reply } = request;
+    let committer = committer.clone();
+    tokio::spawn(async move {
+        let result = committer.commit(meta).await;
+        reply.send(result);
+    });
+    handle.await.map_err(CoordinateError::from)
+}
+
 pub struct SeqMigrationStateSynchronizer<
     PR: ProxiesRetriever,
     SC: MigrationStateChecker,
@@ -388,6 +673,7 @@ pub struct SeqMigrationStateSynchronizer<
     committer: Arc<MC>,
     meta_retriever: Arc<MR>,
     sender: Arc<S>,
+    concurrency: usize,
 }
 
 impl<
@@ -398,6 +684,25 @@ impl<
         S: HostMetaSender,
     > SeqMigrationStateSynchronizer<PR, SC, MC, MR, S>
 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_concurrency(
+        proxy_retriever: PR,
+        checker: SC,
+        committer: MC,
+        meta_retriever: MR,
+        sender: S,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            proxy_retriever,
+            checker: Arc::new(checker),
+            committer: Arc::new(committer),
+            meta_retriever: Arc::new(meta_retriever),
+            sender: Arc::new(sender),
+            concurrency,
+        }
+    }
+
     async fn set_db_meta(
         address: String,
         meta_retriever: &MR,
@@ -416,49 +721,72 @@ impl<
     }
 
     async fn sync_migration_state(
-        commiter: &MC,
+        commiter: &Arc<MC>,
         meta_retriever: &MR,
         sender: &S,
-        meta: MigrationTaskMeta,
+        request: Request<MigrationTaskMeta>,
     ) -> Result<(), CoordinateError> {
-        let (src_address, dst_address) = match meta.slot_range.tag.get_migration_meta() {
-            Some(migration_meta) => (
-                migration_meta.src_proxy_address.clone(),
-                migration_meta.dst_proxy_address.clone(),
-            ),
-            None => {
-                error!("invalid migration task meta {:?}, skip it.", meta);
-                return Ok(());
-            }
-        };
+        let span = request.span();
+        async move {
+            let meta = request.value();
+            let (src_address, dst_address) = match meta.slot_range.tag.get_migration_meta() {
+                Some(migration_meta) => (
+                    migration_meta.src_proxy_address.clone(),
+                    migration_meta.dst_proxy_address.clone(),
+                ),
+                None => {
+                    error!("invalid migration task meta {:?}, skip it.", meta);
+                    return Ok(());
+                }
+            };
 
-        if let Err(err) = commiter.commit(meta).await {
-            error!("failed to commit migration state: {:?}", err);
-            return Err(err);
-        }
+            let meta = request.into_value();
+            if let Err(err) = commit_migration(commiter, meta).await {
+                error!("failed to commit migration state: {:?}", err);
+                Span::current().record("error", field::debug(&err));
+                return Err(err);
+            }
 
-        // Send to dst first to make sure the slots will always have owner.
-        Self::set_db_meta(dst_address, meta_retriever, sender).await?;
-        Self::set_db_meta(src_address, meta_retriever, sender).await?;
+            // Send to dst first to make sure the slots will always have owner.
+            Self::set_db_meta(dst_address, meta_retriever, sender).await?;
+            Self::set_db_meta(src_address, meta_retriever, sender).await?;
 
-        Ok(())
+            Ok(())
+        }
+        .instrument(span)
+        .await
     }
 
     async fn check_and_sync(
         checker: &SC,
-        commiter: &MC,
+        commiter: &Arc<MC>,
         meta_retriever: &MR,
         sender: &S,
-        address: String,
+        request: Request<String>,
     ) -> Result<(), CoordinateError> {
-        for res in checker.check(address).next().await {
-            let meta = match res {
-                Ok(meta) => meta,
-                Err(err) => return Err(err),
-            };
-            Self::sync_migration_state(commiter, meta_retriever, sender, meta).await?;
+        let span = request.span();
+        async move {
+            let address = request.value().clone();
+            for res in checker.check(address).next().await {
+                let meta = match res {
+                    Ok(meta) => meta,
+                    Err(err) => return Err(err),
+                };
+                let meta_request = request.map(move |address| {
+                    let span = tracing::info_span!(
+                        "sync_migration",
+                        address = %address,
+                        slot_range = ?meta.slot_range,
+                        error = field::Empty,
+                    );
+                    (meta, span)
+                });
+                Self::sync_migration_state(commiter, meta_retriever, sender, meta_request).await?;
+            }
+            Ok(())
         }
-        Ok(())
+        .instrument(span)
+        .await
     }
 
     async fn run_impl(&self) -> Result<(), CoordinateError> {
@@ -467,37 +795,47 @@ impl<
         let meta_retriever = self.meta_retriever.clone();
         let sender = self.sender.clone();
 
-        const CHUNK_SIZE: usize = 10;
-        let batch_time = Duration::from_millis(1);
-
         let mut res = Ok(());
-        let mut s = self
+
+        let mut results = self
             .proxy_retriever
             .retrieve_proxies()
-            .chunks_timeout(CHUNK_SIZE, batch_time);
-        for results in s.next().await {
-            let mut proxies = vec![];
-            for r in results {
-                match r {
-                    Ok(proxy) => proxies.push(proxy),
-                    Err(err) => {
-                        error!("failed to get proxy: {:?}", err);
-                        res = Err(err);
+            .map(|r| {
+                let checker = checker.clone();
+                let committer = committer.clone();
+                let meta_retriever = meta_retriever.clone();
+                let sender = sender.clone();
+                async move {
+                    match r {
+                        Ok(address) => {
+                            let span = tracing::info_span!(
+                                "check_and_sync",
+                                address = %address,
+                                error = field::Empty,
+                            );
+                            let request = Request::new(address, span);
+                            Self::check_and_sync(
+                                &checker,
+                                &committer,
+                                &meta_retriever,
+                                &sender,
+                                request,
+                            )
+                            .await
+                        }
+                        Err(err) => {
+                            error!("failed to get proxy: {:?}", err);
+                            Err(err)
+                        }
                     }
                 }
-            }
-            let futs: Vec<_> = proxies
-                .into_iter()
-                .map(|address| {
-                    Self::check_and_sync(&checker, &committer, &meta_retriever, &sender, address)
-                })
-                .collect();
-            let results = future::join_all(futs).await;
-            for r in results.into_iter() {
-                if let Err(err) = r {
-                    error!("faild to sync migration state, error: {:?}", err);
-                    res = Err(err);
-                }
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some(r) = results.next().await {
+            if let Err(err) = r {
+                error!("faild to sync migration state, error: {:?}", err);
+                res = Err(err);
             }
         }
         res
@@ -525,13 +863,14 @@ impl<
         meta_retriever: Self::MRetriever,
         sender: Self::Sender,
     ) -> Self {
-        Self {
+        Self::with_concurrency(
             proxy_retriever,
-            checker: Arc::new(checker),
-            committer: Arc::new(committer),
-            meta_retriever: Arc::new(meta_retriever),
-            sender: Arc::new(sender),
-        }
+            checker,
+            committer,
+            meta_retriever,
+            sender,
+            DEFAULT_MIGRATION_SYNC_CONCURRENCY,
+        )
     }
 
     fn run<'s>(&'s self) -> Pin<Box<dyn Stream<Item = Result<(), CoordinateError>> + Send + 's>> {
@@ -550,6 +889,18 @@ pub enum CoordinateError {
     MetaData(MetaDataBrokerError),
     Redis(RedisClientError),
     InvalidReply,
+    Timeout,
+}
+
+impl From<crate::common::intercom::IntercomError<CoordinateError>> for CoordinateError {
+    fn from(err: crate::common::intercom::IntercomError<CoordinateError>) -> Self {
+        use crate::common::intercom::IntercomError;
+        match err {
+            IntercomError::Timeout => CoordinateError::Timeout,
+            IntercomError::Cancelled => CoordinateError::Timeout,
+            IntercomError::Failed(err) => err,
+        }
+    }
 }
 
 impl fmt::Display for CoordinateError {
@@ -599,4 +950,144 @@ mod tests {
         let checker = DummyChecker {};
         check(checker);
     }
+
+    struct FlakyChecker {
+        remaining_failures: std::sync::atomic::AtomicUsize,
+    }
+
+    impl FailureChecker for FlakyChecker {
+        fn check(
+            &self,
+            address: String,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<String>, CoordinateError>> + Send>> {
+            if self
+                .remaining_failures
+                .fetch_update(
+                    std::sync::atomic::Ordering::SeqCst,
+                    std::sync::atomic::Ordering::SeqCst,
+                    |n| if n == 0 { None } else { Some(n - 1) },
+                )
+                .is_ok()
+            {
+                Box::pin(future::ok(Some(address)))
+            } else {
+                Box::pin(future::ok(None))
+            }
+        }
+    }
+
+    #[test]
+    fn test_recheck_recovers_from_a_transient_blip() {
+        let mut rt = Runtime::new().expect("test_recheck_recovers_from_a_transient_blip");
+        let checker = FlakyChecker {
+            remaining_failures: std::sync::atomic::AtomicUsize::new(RECHECK_ATTEMPTS),
+        };
+        let res = rt.block_on(recheck(&checker, "addr".to_string()));
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_recheck_keeps_a_persistent_failure() {
+        let mut rt = Runtime::new().expect("test_recheck_keeps_a_persistent_failure");
+        let checker = FlakyChecker {
+            remaining_failures: std::sync::atomic::AtomicUsize::new(usize::MAX),
+        };
+        let res = rt.block_on(recheck(&checker, "addr".to_string()));
+        assert_eq!(res.unwrap(), Some("addr".to_string()));
+    }
+
+    #[test]
+    fn test_confirm_failure_waits_for_threshold_and_grace_period() {
+        let failure_states = Mutex::new(HashMap::new());
+        let threshold = 3;
+        let grace_period = Duration::from_millis(50);
+
+        assert!(!confirm_failure(&failure_states, threshold, grace_period, "addr"));
+        assert!(!confirm_failure(&failure_states, threshold, grace_period, "addr"));
+        // The 3rd consecutive failure meets the count threshold but the
+        // grace period has not elapsed yet, so it still should not confirm.
+        assert!(!confirm_failure(&failure_states, threshold, grace_period, "addr"));
+
+        std::thread::sleep(grace_period);
+        assert!(confirm_failure(&failure_states, threshold, grace_period, "addr"));
+    }
+
+    #[test]
+    fn test_clear_failure_resets_the_counter() {
+        let failure_states = Mutex::new(HashMap::new());
+        let threshold = 1;
+        let grace_period = Duration::from_millis(0);
+
+        assert!(confirm_failure(&failure_states, threshold, grace_period, "addr"));
+        clear_failure(&failure_states, "addr");
+        assert!(!failure_states.lock().unwrap().contains_key("addr"));
+
+        // After clearing, the streak starts over from one failed round.
+        assert!(confirm_failure(&failure_states, threshold, grace_period, "addr"));
+    }
+
+    struct TwoRoundRetriever {
+        round: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ProxiesRetriever for TwoRoundRetriever {
+        fn retrieve_proxies(
+            &self,
+        ) -> Pin<Box<dyn Stream<Item = Result<String, CoordinateError>> + Send + '_>> {
+            let round = self.round.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let addresses = if round == 0 {
+                vec!["a".to_string(), "b".to_string()]
+            } else {
+                vec!["a".to_string()]
+            };
+            Box::pin(stream::iter(addresses.into_iter().map(Ok)))
+        }
+    }
+
+    struct AlwaysFailingChecker {}
+
+    impl FailureChecker for AlwaysFailingChecker {
+        fn check(
+            &self,
+            address: String,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<String>, CoordinateError>> + Send>> {
+            Box::pin(future::ok(Some(address)))
+        }
+    }
+
+    struct UnreachableReporter {}
+
+    impl FailureReporter for UnreachableReporter {
+        fn report(
+            &self,
+            _address: String,
+        ) -> Pin<Box<dyn Future<Output = Result<(), CoordinateError>> + Send>> {
+            unreachable!("failure_threshold is never met in this test")
+        }
+    }
+
+    #[test]
+    fn test_run_impl_evicts_failure_state_for_addresses_no_longer_retrieved() {
+        let mut rt = Runtime::new().expect("test_run_impl_evicts_failure_state_for_addresses_no_longer_retrieved");
+        let detector = SeqFailureDetector::with_concurrency(
+            TwoRoundRetriever {
+                round: std::sync::atomic::AtomicUsize::new(0),
+            },
+            AlwaysFailingChecker {},
+            UnreachableReporter {},
+            10,
+            5,
+            Duration::from_secs(9999),
+        );
+
+        rt.block_on(detector.run_impl()).unwrap();
+        assert!(detector.failure_states.lock().unwrap().contains_key("a"));
+        assert!(detector.failure_states.lock().unwrap().contains_key("b"));
+
+        // "b" is no longer returned by the retriever on the second round, so
+        // its debounce state should be evicted instead of lingering forever.
+        rt.block_on(detector.run_impl()).unwrap();
+        assert!(detector.failure_states.lock().unwrap().contains_key("a"));
+        assert!(!detector.failure_states.lock().unwrap().contains_key("b"));
+    }
 }
@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+/// Whether every completed command is logged, and the slowlog threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestLogConfig {
+    pub enabled: bool,
+    pub slowlog_threshold_micros: u64,
+}
+
+impl RequestLogConfig {
+    fn is_slow(&self, latency: Duration) -> bool {
+        self.slowlog_threshold_micros > 0
+            && latency.as_micros() as u64 >= self.slowlog_threshold_micros
+    }
+
+    fn should_log(&self, latency: Duration) -> bool {
+        self.enabled || self.is_slow(latency)
+    }
+}
+
+/// A finished command on the forwarding path, ready to be logged.
+pub struct CommandLogEntry<'a> {
+    pub command: &'a str,
+    pub backend_address: &'a str,
+    pub latency: Duration,
+    pub reply_size: usize,
+}
+
+/// Logs `entry` if `config.enabled` or its latency crossed the slowlog threshold.
+pub fn log_completed_command(config: &RequestLogConfig, entry: CommandLogEntry) {
+    if !config.should_log(entry.latency) {
+        return;
+    }
+
+    if config.is_slow(entry.latency) {
+        warn!(
+            "slowlog: command={} backend={} latency_us={} reply_size={}",
+            entry.command,
+            entry.backend_address,
+            entry.latency.as_micros(),
+            entry.reply_size
+        );
+    } else {
+        info!(
+            "completed: command={} backend={} latency_us={} reply_size={}",
+            entry.command,
+            entry.backend_address,
+            entry.latency.as_micros(),
+            entry.reply_size
+        );
+    }
+}
+
+/// Captures the start/stop timestamps of a single forwarded command.
+pub struct CommandTimer {
+    start: Instant,
+}
+
+impl CommandTimer {
+    pub fn start() -> Self {
+        CommandTimer {
+            start: Instant::now(),
+        }
+    }
+
+    pub fn finish(self, config: &RequestLogConfig, command: &str, backend_address: &str, reply_size: usize) {
+        log_completed_command(
+            config,
+            CommandLogEntry {
+                command,
+                backend_address,
+                latency: self.start.elapsed(),
+                reply_size,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_and_under_threshold_does_not_log() {
+        let config = RequestLogConfig {
+            enabled: false,
+            slowlog_threshold_micros: 1000,
+        };
+        assert!(!config.should_log(Duration::from_micros(500)));
+    }
+
+    #[test]
+    fn test_enabled_always_logs() {
+        let config = RequestLogConfig {
+            enabled: true,
+            slowlog_threshold_micros: 0,
+        };
+        assert!(config.should_log(Duration::from_micros(1)));
+    }
+
+    #[test]
+    fn test_slowlog_triggers_regardless_of_enabled() {
+        let config = RequestLogConfig {
+            enabled: false,
+            slowlog_threshold_micros: 1000,
+        };
+        assert!(config.should_log(Duration::from_micros(1500)));
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_slowlog() {
+        let config = RequestLogConfig {
+            enabled: false,
+            slowlog_threshold_micros: 0,
+        };
+        assert!(!config.should_log(Duration::from_secs(1)));
+    }
+}